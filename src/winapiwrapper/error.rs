@@ -0,0 +1,87 @@
+use std::fmt;
+use winapi::shared::minwindef::DWORD;
+use winapi::um::errhandlingapi::GetLastError;
+use winapi::um::winbase::{
+    FormatMessageW, FORMAT_MESSAGE_ALLOCATE_BUFFER, FORMAT_MESSAGE_FROM_SYSTEM,
+    FORMAT_MESSAGE_IGNORE_INSERTS,
+};
+use winapi::um::winnt::{LANG_NEUTRAL, MAKELANGID, SUBLANG_DEFAULT};
+
+/// Errors produced by this crate's `winapiwrapper` layer.
+#[derive(Debug)]
+pub enum Error {
+    /// A Win32 API call failed; carries the call's name, its `GetLastError` code, and the
+    /// code's `FormatMessage` description so failures are machine-distinguishable (e.g.
+    /// access-denied vs. a partial copy) rather than identical opaque strings.
+    Win32 {
+        call: &'static str,
+        code: DWORD,
+        message: String,
+    },
+    /// Anything else that doesn't originate from a single Win32 call with a `GetLastError`
+    /// code attached (argument validation, partial reads, etc).
+    Other(String),
+}
+
+impl Error {
+    pub fn new(message: String) -> Self {
+        Error::Other(message)
+    }
+
+    /// Builds an error for `call` from the calling thread's current `GetLastError()`.
+    pub fn last_os_error(call: &'static str) -> Self {
+        let code = unsafe { GetLastError() };
+
+        Error::Win32 {
+            call,
+            code,
+            message: format_message(code),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Win32 {
+                call,
+                code,
+                message,
+            } => write!(f, "{} failed with code {}: {}", call, code, message),
+            Error::Other(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn format_message(code: DWORD) -> String {
+    let mut buffer: *mut u16 = std::ptr::null_mut();
+
+    let len = unsafe {
+        FormatMessageW(
+            FORMAT_MESSAGE_ALLOCATE_BUFFER | FORMAT_MESSAGE_FROM_SYSTEM | FORMAT_MESSAGE_IGNORE_INSERTS,
+            std::ptr::null(),
+            code,
+            MAKELANGID(LANG_NEUTRAL, SUBLANG_DEFAULT) as u32,
+            &mut buffer as *mut *mut u16 as *mut u16,
+            0,
+            std::ptr::null_mut(),
+        )
+    };
+
+    if len == 0 || buffer.is_null() {
+        return format!("unknown error {:#x}", code);
+    }
+
+    let message = unsafe {
+        let slice = std::slice::from_raw_parts(buffer, len as usize);
+        String::from_utf16_lossy(slice)
+    };
+
+    unsafe {
+        winapi::um::winbase::LocalFree(buffer as *mut winapi::ctypes::c_void);
+    }
+
+    message.trim_end().to_string()
+}
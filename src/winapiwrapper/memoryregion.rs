@@ -0,0 +1,118 @@
+use super::error::Error;
+use super::handleowner::HandleOwner;
+use super::process::Process;
+use super::protectflag::ProtectFlag;
+use super::regionstate::RegionState;
+use super::regiontype::RegionType;
+use std::mem::{size_of, MaybeUninit};
+use winapi::shared::minwindef::LPVOID;
+use winapi::um::memoryapi::VirtualQueryEx;
+use winapi::um::winnt::MEMORY_BASIC_INFORMATION;
+
+/// A single region of a process's address space, as reported by `VirtualQueryEx`.
+#[derive(Debug, Clone, Copy)]
+pub struct MemoryRegion {
+    base_address: usize,
+    size: usize,
+    state: RegionState,
+    protect: Option<ProtectFlag>,
+    region_type: Option<RegionType>,
+}
+
+impl MemoryRegion {
+    pub fn base_address(&self) -> usize {
+        self.base_address
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn state(&self) -> RegionState {
+        self.state
+    }
+
+    pub fn protect(&self) -> Option<ProtectFlag> {
+        self.protect
+    }
+
+    pub fn region_type(&self) -> Option<RegionType> {
+        self.region_type
+    }
+}
+
+/// Iterator over a process's address space, yielded by `Process::memory_regions`. Walks
+/// forward from address 0 via successive `VirtualQueryEx` calls until one reports nothing
+/// left to query.
+pub struct MemoryRegionIter<'a> {
+    process: &'a Process,
+    next_address: usize,
+    done: bool,
+}
+
+impl<'a> MemoryRegionIter<'a> {
+    pub(crate) fn new(process: &'a Process) -> Self {
+        Self {
+            process,
+            next_address: 0,
+            done: false,
+        }
+    }
+}
+
+impl<'a> Iterator for MemoryRegionIter<'a> {
+    type Item = Result<MemoryRegion, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut mbi = MaybeUninit::<MEMORY_BASIC_INFORMATION>::uninit();
+
+        let ret = unsafe {
+            VirtualQueryEx(
+                self.process.handle(),
+                self.next_address as LPVOID,
+                mbi.as_mut_ptr(),
+                size_of::<MEMORY_BASIC_INFORMATION>(),
+            )
+        };
+
+        if ret == 0 {
+            self.done = true;
+            return None;
+        }
+
+        let mbi = unsafe { mbi.assume_init() };
+
+        let state = match RegionState::from_bits(mbi.State) {
+            Some(state) => state,
+            None => {
+                self.done = true;
+                return Some(Err(Error::new(format!(
+                    "VirtualQueryEx returned unrecognised state {:#x}",
+                    mbi.State
+                ))));
+            }
+        };
+
+        let region = MemoryRegion {
+            base_address: mbi.BaseAddress as usize,
+            size: mbi.RegionSize,
+            state,
+            protect: ProtectFlag::from_bits(mbi.Protect),
+            region_type: RegionType::from_bits(mbi.Type),
+        };
+
+        let next_address = region.base_address + region.size;
+
+        if next_address <= self.next_address {
+            self.done = true;
+        } else {
+            self.next_address = next_address;
+        }
+
+        Some(Ok(region))
+    }
+}
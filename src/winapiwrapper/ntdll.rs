@@ -0,0 +1,23 @@
+use winapi::ctypes::c_void;
+use winapi::shared::minwindef::ULONG;
+use winapi::shared::ntdef::NTSTATUS;
+use winapi::um::winnt::HANDLE;
+
+/// `PROCESSINFOCLASS`, the `NtQueryInformationProcess` info-class selector. `winapi` doesn't
+/// expose `um::winternl` (those undocumented NT APIs live in the separate `ntapi` crate, which
+/// this crate doesn't depend on), so the handful of pieces needed here are hand-declared.
+pub type ProcessInfoClass = i32;
+
+pub const PROCESS_BASIC_INFORMATION_CLASS: ProcessInfoClass = 0;
+pub const PROCESS_WOW64_INFORMATION_CLASS: ProcessInfoClass = 26;
+
+#[link(name = "ntdll")]
+extern "system" {
+    pub fn NtQueryInformationProcess(
+        process_handle: HANDLE,
+        process_information_class: ProcessInfoClass,
+        process_information: *mut c_void,
+        process_information_length: ULONG,
+        return_length: *mut ULONG,
+    ) -> NTSTATUS;
+}
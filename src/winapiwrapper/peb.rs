@@ -0,0 +1,72 @@
+use winapi::shared::ntdef::UNICODE_STRING;
+use winapi::um::winnt::PVOID;
+
+/// Mirrors `ntdll`'s (undocumented but stable) `PROCESS_BASIC_INFORMATION`, as returned by
+/// `NtQueryInformationProcess(ProcessBasicInformation)` for a native (same-bitness) target.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct ProcessBasicInfo {
+    pub reserved1: PVOID,
+    pub peb_base_address: PVOID,
+    pub reserved2: [PVOID; 2],
+    pub unique_process_id: usize,
+    pub reserved3: PVOID,
+}
+
+/// The leading fields of the native `PEB` up to and including `ProcessParameters`. The
+/// remaining (much larger) structure isn't read by this crate, so it's omitted.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Peb {
+    pub reserved1: [u8; 2],
+    pub being_debugged: u8,
+    pub reserved2: [u8; 1],
+    pub reserved3: [PVOID; 2],
+    pub ldr: PVOID,
+    pub process_parameters: PVOID,
+}
+
+/// The leading fields of `RTL_USER_PROCESS_PARAMETERS` up to and including `Environment`.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct RtlUserProcessParameters {
+    pub reserved1: [u8; 16],
+    pub reserved2: [PVOID; 10],
+    pub image_path_name: UNICODE_STRING,
+    pub command_line: UNICODE_STRING,
+    pub environment: PVOID,
+}
+
+/// 32-bit `UNICODE_STRING` layout, for reading a WOW64 target's `PEB32`.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct UnicodeString32 {
+    pub length: u16,
+    pub maximum_length: u16,
+    pub buffer: u32,
+}
+
+/// The leading fields of a WOW64 target's `PEB32` up to and including `ProcessParameters`.
+/// It is read from the address that `NtQueryInformationProcess(ProcessWow64Information)`
+/// yields; that call returns only the `PEB32` address, not a full
+/// `PROCESS_BASIC_INFORMATION32`.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Peb32 {
+    pub reserved1: [u8; 2],
+    pub being_debugged: u8,
+    pub reserved2: [u8; 1],
+    pub reserved3: [u32; 2],
+    pub ldr: u32,
+    pub process_parameters: u32,
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct RtlUserProcessParameters32 {
+    pub reserved1: [u8; 16],
+    pub reserved2: [u32; 10],
+    pub image_path_name: UnicodeString32,
+    pub command_line: UnicodeString32,
+    pub environment: u32,
+}
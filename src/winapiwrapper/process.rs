@@ -1,33 +1,50 @@
 use super::error::Error;
 use super::handleowner::HandleOwner;
+use super::memoryregion::MemoryRegionIter;
+use super::ntdll::{
+    NtQueryInformationProcess, PROCESS_BASIC_INFORMATION_CLASS, PROCESS_WOW64_INFORMATION_CLASS,
+};
+use super::peb::{Peb, Peb32, ProcessBasicInfo, RtlUserProcessParameters, RtlUserProcessParameters32};
 use super::processaccess::ProcessAccess;
 use super::protectflag::ProtectFlag;
+use super::remotealloc::RemoteAlloc;
 use super::snapshot::Snapshot;
 use super::snapshotflags::SnapshotFlags;
 use super::thread::Thread;
 use super::threadaccess::ThreadAccess;
+use std::ffi::OsString;
+use std::mem::size_of;
 use std::ops::Drop;
+use std::os::windows::ffi::{OsStrExt, OsStringExt};
+use std::path::Path;
+use std::ptr;
 use winapi::ctypes::c_void;
 use winapi::shared::minwindef::LPVOID;
+use winapi::shared::ntdef::NTSTATUS;
 use winapi::um::handleapi::CloseHandle;
+use winapi::um::libloaderapi::{GetModuleHandleA, GetProcAddress};
 use winapi::um::memoryapi::{ReadProcessMemory, VirtualProtectEx, WriteProcessMemory};
-use winapi::um::processthreadsapi::{GetProcessId, OpenProcess, GetCurrentProcess};
+use winapi::um::processthreadsapi::{CreateRemoteThread, GetCurrentProcess, GetProcessId, OpenProcess};
 use winapi::um::winnt::HANDLE;
 
 pub struct Process {
     handle: HANDLE,
+    // Only `from_pid`-opened handles are ours to close; pseudo-handles (e.g. from
+    // `from_current`) and other borrowed handles must outlive this wrapper.
+    owned: bool,
 }
 
-// TODO: Close handle on drop if opened by OpenProcess
-
 impl Process {
     pub fn from_pid(pid: u32, access: ProcessAccess, inherit: bool) -> Result<Self, Error> {
         let handle = unsafe { OpenProcess(access.bits(), inherit as i32, pid) };
 
         if handle.is_null() {
-            Err(Error::new("OpenProcess returned NULL".to_string()))
+            Err(Error::last_os_error("OpenProcess"))
         } else {
-            Ok(Self { handle })
+            Ok(Self {
+                handle,
+                owned: true,
+            })
         }
     }
 
@@ -101,7 +118,7 @@ impl Process {
         };
 
         if ret == 0 {
-            Err(Error::new("WriteProcessMemory failed".to_string()))
+            Err(Error::last_os_error("WriteProcessMemory"))
         } else {
             Ok(num_bytes_written)
         }
@@ -129,7 +146,7 @@ impl Process {
         };
 
         if ret == 0 {
-            Err(Error::new("ReadProcessMemory failed".to_string()))
+            Err(Error::last_os_error("ReadProcessMemory"))
         } else {
             Ok(num_bytes_read)
         }
@@ -159,23 +176,453 @@ impl Process {
         if ret != 0 {
             Ok(old_protect)
         } else {
-            Err(Error::new("VirtualProtectEx returned NULL".to_string()))
+            Err(Error::last_os_error("VirtualProtectEx"))
+        }
+    }
+
+    /// Walks this process's address space via repeated `VirtualQueryEx` calls, yielding each
+    /// region in ascending address order.
+    pub fn memory_regions(&self) -> MemoryRegionIter {
+        MemoryRegionIter::new(self)
+    }
+
+    /// Returns the target's command line, recovered by walking its PEB.
+    pub fn command_line(&self) -> Result<OsString, Error> {
+        self.read_unicode_field(true)
+    }
+
+    /// Returns the path to the target's main executable, recovered by walking its PEB.
+    pub fn image_path(&self) -> Result<OsString, Error> {
+        self.read_unicode_field(false)
+    }
+
+    /// Returns the target's environment block as a list of `"KEY=VALUE"` entries, recovered
+    /// by walking its PEB to the environment buffer and reading until a double NUL.
+    pub fn environment(&self) -> Result<Vec<OsString>, Error> {
+        let env_addr = if let Some(peb32_addr) = self.wow64_peb_address()? {
+            let peb32: Peb32 = unsafe { self.read(peb32_addr) }?;
+            let params: RtlUserProcessParameters32 =
+                unsafe { self.read(peb32.process_parameters as usize) }?;
+            params.environment as usize
+        } else {
+            let peb_addr = self.peb_address()?;
+            let peb: Peb = unsafe { self.read(peb_addr) }?;
+            let params: RtlUserProcessParameters = unsafe { self.read(peb.process_parameters as usize) }?;
+            params.environment as usize
+        };
+
+        let wide = self.read_wide_string_until_double_nul(env_addr)?;
+
+        Ok(wide
+            .split(|&c| c == 0)
+            .filter(|s| !s.is_empty())
+            .map(OsString::from_wide)
+            .collect())
+    }
+
+    /// Returns `Some(peb32_address)` if this process is a WOW64 (32-bit on 64-bit OS)
+    /// process, `None` if it is running natively.
+    fn wow64_peb_address(&self) -> Result<Option<usize>, Error> {
+        let mut peb32_addr: usize = 0;
+        let mut return_length: u32 = 0;
+
+        let status = unsafe {
+            NtQueryInformationProcess(
+                self.handle,
+                PROCESS_WOW64_INFORMATION_CLASS,
+                &mut peb32_addr as *mut usize as *mut c_void,
+                size_of::<usize>() as u32,
+                &mut return_length,
+            )
+        };
+
+        if status != 0 {
+            return Err(Error::new(format!(
+                "NtQueryInformationProcess(ProcessWow64Information) failed with status {:#x}",
+                status
+            )));
+        }
+
+        Ok(if peb32_addr == 0 {
+            None
+        } else {
+            Some(peb32_addr)
+        })
+    }
+
+    /// Returns the address of this process's native PEB.
+    fn peb_address(&self) -> Result<usize, Error> {
+        let mut info = ProcessBasicInfo {
+            reserved1: ptr::null_mut(),
+            peb_base_address: ptr::null_mut(),
+            reserved2: [ptr::null_mut(); 2],
+            unique_process_id: 0,
+            reserved3: ptr::null_mut(),
+        };
+        let mut return_length: u32 = 0;
+
+        let status: NTSTATUS = unsafe {
+            NtQueryInformationProcess(
+                self.handle,
+                PROCESS_BASIC_INFORMATION_CLASS,
+                &mut info as *mut ProcessBasicInfo as *mut c_void,
+                size_of::<ProcessBasicInfo>() as u32,
+                &mut return_length,
+            )
+        };
+
+        if status != 0 {
+            return Err(Error::new(format!(
+                "NtQueryInformationProcess(ProcessBasicInformation) failed with status {:#x}",
+                status
+            )));
+        }
+
+        Ok(info.peb_base_address as usize)
+    }
+
+    /// Reads a `T` out of the target's address space at `address`.
+    ///
+    /// # Safety
+    ///
+    /// `Copy` only says `T` can be duplicated byte-for-byte; it says nothing about which bit
+    /// patterns are valid. The caller must ensure the bytes at `address` are a valid `T`
+    /// (e.g. don't read into a `bool` or `char` from memory that isn't known to hold one).
+    pub unsafe fn read<T: Copy>(&self, address: usize) -> Result<T, Error> {
+        let mut value = std::mem::MaybeUninit::<T>::uninit();
+        let buffer = unsafe {
+            std::slice::from_raw_parts_mut(value.as_mut_ptr() as *mut u8, size_of::<T>())
+        };
+
+        let num_read = self.read_memory(buffer, address)?;
+
+        if num_read != size_of::<T>() {
+            return Err(Error::new(
+                "Partial read: fewer bytes returned than requested".to_string(),
+            ));
+        }
+
+        Ok(unsafe { value.assume_init() })
+    }
+
+    /// Writes `value` into the target's address space at `address`.
+    pub fn write<T: Copy>(&self, address: usize, value: &T) -> Result<(), Error> {
+        let buffer =
+            unsafe { std::slice::from_raw_parts(value as *const T as *const u8, size_of::<T>()) };
+
+        let num_written = self.write_memory(buffer, address)?;
+
+        if num_written != size_of::<T>() {
+            return Err(Error::new(
+                "Partial write: fewer bytes written than requested".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Reads `count` contiguous `T`s out of the target's address space starting at `address`.
+    ///
+    /// # Safety
+    ///
+    /// Same caveat as `read`: `Copy` doesn't attest to which bit patterns are valid for `T`,
+    /// so the caller must ensure the target's memory at `address` actually holds `count`
+    /// valid `T`s.
+    pub unsafe fn read_slice<T: Copy>(&self, address: usize, count: usize) -> Result<Vec<T>, Error> {
+        // `Vec::with_capacity` allocates memory aligned for `T`, unlike a `Vec<u8>` buffer,
+        // which is only guaranteed byte-aligned and isn't safe to reinterpret as `[T]`.
+        let mut values: Vec<T> = Vec::with_capacity(count);
+        let buffer = unsafe {
+            std::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut u8, size_of::<T>() * count)
+        };
+
+        let num_read = self.read_memory(buffer, address)?;
+
+        if num_read != buffer.len() {
+            return Err(Error::new(
+                "Partial read: fewer bytes returned than requested".to_string(),
+            ));
+        }
+
+        unsafe { values.set_len(count) };
+
+        Ok(values)
+    }
+
+    /// Reads a wide-char buffer of `len_bytes` bytes at `address` out of the target.
+    fn read_wide_string(&self, address: usize, len_bytes: u16) -> Result<OsString, Error> {
+        if address == 0 || len_bytes == 0 {
+            return Ok(OsString::new());
+        }
+
+        let mut buffer = vec![0u16; len_bytes as usize / 2];
+        let byte_buffer = unsafe {
+            std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, len_bytes as usize)
+        };
+
+        self.read_memory(byte_buffer, address)?;
+
+        Ok(OsString::from_wide(&buffer))
+    }
+
+    /// Reads wide chars at `address` in the target until a double NUL terminator is found.
+    fn read_wide_string_until_double_nul(&self, address: usize) -> Result<Vec<u16>, Error> {
+        if address == 0 {
+            return Ok(Vec::new());
+        }
+
+        const PAGE_SIZE: usize = 0x1000;
+        let mut result = Vec::new();
+        let mut offset = 0usize;
+        // The last u16 of the previous chunk, so a terminator split across a chunk boundary
+        // (one zero u16 as a chunk's last element, its pair as the next chunk's first) isn't
+        // missed by a `windows(2)` scan confined to a single chunk.
+        let mut prev_tail: Option<u16> = None;
+
+        loop {
+            let cur_addr = address + offset;
+
+            // Never request a read spanning past the end of the current page: if the
+            // double-NUL terminator sits just before an unmapped page, a chunk read
+            // crossing into it would fail outright even though we'd never need those bytes.
+            let mut chunk_len = PAGE_SIZE - (cur_addr % PAGE_SIZE);
+
+            let chunk_u16 = loop {
+                let mut chunk = vec![0u8; chunk_len];
+
+                match self.read_memory(&mut chunk, cur_addr) {
+                    Ok(_) => {
+                        break chunk
+                            .chunks_exact(2)
+                            .map(|b| u16::from_ne_bytes([b[0], b[1]]))
+                            .collect::<Vec<u16>>()
+                    }
+                    Err(e) => {
+                        // The chunk itself may reach into memory the target hasn't mapped;
+                        // halve and retry before giving up.
+                        if chunk_len <= 2 {
+                            return Err(e);
+                        }
+
+                        chunk_len /= 2;
+                    }
+                }
+            };
+
+            if accumulate_chunk_until_double_nul(&mut result, &mut prev_tail, &chunk_u16) {
+                break;
+            }
+
+            offset += chunk_u16.len() * 2;
+        }
+
+        Ok(result)
+    }
+
+    /// Fetches either `CommandLine` (`is_command_line == true`) or `ImagePathName` from the
+    /// target's `RTL_USER_PROCESS_PARAMETERS`, transparently handling WOW64 targets.
+    fn read_unicode_field(&self, is_command_line: bool) -> Result<OsString, Error> {
+        if let Some(peb32_addr) = self.wow64_peb_address()? {
+            let peb32: Peb32 = unsafe { self.read(peb32_addr) }?;
+            let params: RtlUserProcessParameters32 =
+                unsafe { self.read(peb32.process_parameters as usize) }?;
+            let (buffer, len) = if is_command_line {
+                (params.command_line.buffer as usize, params.command_line.length)
+            } else {
+                (
+                    params.image_path_name.buffer as usize,
+                    params.image_path_name.length,
+                )
+            };
+
+            self.read_wide_string(buffer, len)
+        } else {
+            let peb_addr = self.peb_address()?;
+            let peb: Peb = unsafe { self.read(peb_addr) }?;
+            let params: RtlUserProcessParameters = unsafe { self.read(peb.process_parameters as usize) }?;
+            let (buffer, len) = if is_command_line {
+                (params.command_line.Buffer as usize, params.command_line.Length)
+            } else {
+                (
+                    params.image_path_name.Buffer as usize,
+                    params.image_path_name.Length,
+                )
+            };
+
+            self.read_wide_string(buffer, len)
         }
     }
+
+    /// Reserves and commits `size` bytes of memory in this process via `VirtualAllocEx`,
+    /// returning a `RemoteAlloc` guard that frees the allocation on drop.
+    pub fn allocate(&self, size: usize, protect: ProtectFlag) -> Result<RemoteAlloc, Error> {
+        RemoteAlloc::new(self, size, protect)
+    }
+
+    /// Injects the DLL at `dll_path` into this process by allocating a buffer for the
+    /// path in the target's address space, writing the path into it, and starting a
+    /// remote thread at `LoadLibraryW` with the buffer as its argument.
+    ///
+    /// Returns the spawned `Thread` together with the `RemoteAlloc` holding the path
+    /// buffer. The buffer must stay alive until the remote thread has read it, so callers
+    /// should wait on the `Thread` (e.g. `WaitForSingleObject`) before letting the
+    /// `RemoteAlloc` drop and free it.
+    pub fn inject_dll(&self, dll_path: &Path) -> Result<(Thread, RemoteAlloc), Error> {
+        let path = dll_path
+            .canonicalize()
+            .map_err(|e| Error::new(format!("Failed to resolve DLL path: {}", e)))?;
+
+        let mut wide_path: Vec<u16> = path.as_os_str().encode_wide().collect();
+        wide_path.push(0);
+
+        let path_bytes = unsafe {
+            std::slice::from_raw_parts(wide_path.as_ptr() as *const u8, wide_path.len() * 2)
+        };
+
+        let remote_alloc = self.allocate(path_bytes.len(), ProtectFlag::PAGE_READWRITE)?;
+        let num_written = remote_alloc.write(path_bytes)?;
+
+        if num_written != path_bytes.len() {
+            return Err(Error::new(
+                "Partial write: fewer bytes written than requested".to_string(),
+            ));
+        }
+
+        let load_library_w = unsafe {
+            let kernel32 = GetModuleHandleA(b"kernel32.dll\0".as_ptr() as *const i8);
+
+            if kernel32.is_null() {
+                return Err(Error::last_os_error("GetModuleHandleA"));
+            }
+
+            GetProcAddress(kernel32, b"LoadLibraryW\0".as_ptr() as *const i8)
+        };
+
+        if load_library_w.is_null() {
+            return Err(Error::last_os_error("GetProcAddress"));
+        }
+
+        let thread_handle = unsafe {
+            CreateRemoteThread(
+                self.handle,
+                ptr::null_mut(),
+                0,
+                Some(std::mem::transmute(load_library_w)),
+                remote_alloc.address() as LPVOID,
+                0,
+                ptr::null_mut(),
+            )
+        };
+
+        if thread_handle.is_null() {
+            return Err(Error::last_os_error("CreateRemoteThread"));
+        }
+
+        Ok((unsafe { Thread::from_handle(thread_handle) }, remote_alloc))
+    }
 }
 
 impl Drop for Process {
     fn drop(&mut self) {
-        self.close().unwrap();
+        if self.owned {
+            let _ = self.close();
+        }
     }
 }
 
 impl HandleOwner for Process {
     unsafe fn from_handle(handle: HANDLE) -> Self {
-        Self { handle }
+        Self {
+            handle,
+            owned: false,
+        }
     }
 
     fn handle(&self) -> HANDLE {
         self.handle
     }
 }
+
+/// Appends `chunk` to `result`, stopping at (and including up to) a double-NUL terminator.
+/// `prev_tail` carries the last `u16` of the previous chunk across calls so a terminator split
+/// across a chunk boundary isn't missed by a scan confined to a single chunk. Returns `true`
+/// once the terminator has been found, signalling the caller to stop reading further chunks.
+fn accumulate_chunk_until_double_nul(
+    result: &mut Vec<u16>,
+    prev_tail: &mut Option<u16>,
+    chunk: &[u16],
+) -> bool {
+    if *prev_tail == Some(0) && chunk.first() == Some(&0) {
+        // `result` already ends with the zero u16 carried over as `prev_tail`; its pair is
+        // this chunk's first element, so the string ended in the previous chunk and nothing
+        // from this one belongs to it.
+        return true;
+    }
+
+    if let Some(pos) = chunk.windows(2).position(|w| w[0] == 0 && w[1] == 0) {
+        result.extend_from_slice(&chunk[..=pos]);
+        return true;
+    }
+
+    *prev_tail = chunk.last().copied();
+    result.extend_from_slice(chunk);
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::accumulate_chunk_until_double_nul;
+
+    #[test]
+    fn double_nul_within_a_single_chunk_terminates() {
+        let mut result = Vec::new();
+        let mut prev_tail = None;
+
+        let done = accumulate_chunk_until_double_nul(
+            &mut result,
+            &mut prev_tail,
+            &[b'A' as u16, b'=' as u16, b'1' as u16, 0, 0, b'X' as u16],
+        );
+
+        assert!(done);
+        assert_eq!(result, vec![b'A' as u16, b'=' as u16, b'1' as u16, 0, 0]);
+    }
+
+    #[test]
+    fn double_nul_split_across_chunk_boundary_terminates() {
+        let mut result = Vec::new();
+        let mut prev_tail = None;
+
+        // First chunk ends with a single NUL; its pair is the first element of the next
+        // chunk. A `windows(2)` scan confined to either chunk alone would miss this.
+        let done = accumulate_chunk_until_double_nul(
+            &mut result,
+            &mut prev_tail,
+            &[b'A' as u16, b'=' as u16, b'1' as u16, 0],
+        );
+        assert!(!done);
+        assert_eq!(prev_tail, Some(0));
+
+        let done =
+            accumulate_chunk_until_double_nul(&mut result, &mut prev_tail, &[0, b'B' as u16]);
+
+        assert!(done);
+        assert_eq!(result, vec![b'A' as u16, b'=' as u16, b'1' as u16, 0]);
+    }
+
+    #[test]
+    fn no_terminator_carries_whole_chunk_forward() {
+        let mut result = Vec::new();
+        let mut prev_tail = None;
+
+        let done = accumulate_chunk_until_double_nul(
+            &mut result,
+            &mut prev_tail,
+            &[b'A' as u16, b'=' as u16, b'1' as u16],
+        );
+
+        assert!(!done);
+        assert_eq!(prev_tail, Some(b'1' as u16));
+        assert_eq!(result, vec![b'A' as u16, b'=' as u16, b'1' as u16]);
+    }
+}
@@ -0,0 +1,20 @@
+use winapi::um::winnt::{MEM_COMMIT, MEM_FREE, MEM_RESERVE};
+
+/// The commit state of a `MemoryRegion`, mirroring `MEMORY_BASIC_INFORMATION::State`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegionState {
+    Commit,
+    Free,
+    Reserve,
+}
+
+impl RegionState {
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        match bits {
+            MEM_COMMIT => Some(RegionState::Commit),
+            MEM_FREE => Some(RegionState::Free),
+            MEM_RESERVE => Some(RegionState::Reserve),
+            _ => None,
+        }
+    }
+}
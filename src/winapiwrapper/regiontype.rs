@@ -0,0 +1,21 @@
+use winapi::um::winnt::{MEM_IMAGE, MEM_MAPPED, MEM_PRIVATE};
+
+/// The kind of a `MemoryRegion`, mirroring `MEMORY_BASIC_INFORMATION::Type`. `Free` regions
+/// carry no type, hence callers see this wrapped in `Option`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegionType {
+    Image,
+    Mapped,
+    Private,
+}
+
+impl RegionType {
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        match bits {
+            MEM_IMAGE => Some(RegionType::Image),
+            MEM_MAPPED => Some(RegionType::Mapped),
+            MEM_PRIVATE => Some(RegionType::Private),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use super::error::Error;
+use super::handleowner::HandleOwner;
+use super::process::Process;
+use super::protectflag::ProtectFlag;
+use std::ops::Drop;
+use std::ptr;
+use winapi::ctypes::c_void;
+use winapi::um::memoryapi::{VirtualAllocEx, VirtualFreeEx};
+use winapi::um::winnt::{MEM_COMMIT, MEM_RELEASE, MEM_RESERVE};
+
+/// A region of memory allocated in a remote process via `VirtualAllocEx`.
+///
+/// The allocation is tied to the lifetime of the `Process` it was carved out of and is
+/// released with `VirtualFreeEx` when dropped.
+pub struct RemoteAlloc<'a> {
+    process: &'a Process,
+    address: usize,
+    len: usize,
+}
+
+impl<'a> RemoteAlloc<'a> {
+    pub fn new(process: &'a Process, size: usize, protect: ProtectFlag) -> Result<Self, Error> {
+        let address = unsafe {
+            VirtualAllocEx(
+                process.handle(),
+                ptr::null_mut(),
+                size,
+                MEM_COMMIT | MEM_RESERVE,
+                protect.bits(),
+            )
+        };
+
+        if address.is_null() {
+            Err(Error::last_os_error("VirtualAllocEx"))
+        } else {
+            Ok(Self {
+                process,
+                address: address as usize,
+                len: size,
+            })
+        }
+    }
+
+    pub fn address(&self) -> usize {
+        self.address
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn write(&self, data: &[u8]) -> Result<usize, Error> {
+        if data.len() > self.len {
+            return Err(Error::new(format!(
+                "Write of {} bytes exceeds allocation size {}",
+                data.len(),
+                self.len
+            )));
+        }
+
+        self.process.write_memory(data, self.address)
+    }
+
+    pub fn read_into(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        if buffer.len() > self.len {
+            return Err(Error::new(format!(
+                "Read of {} bytes exceeds allocation size {}",
+                buffer.len(),
+                self.len
+            )));
+        }
+
+        self.process.read_memory(buffer, self.address)
+    }
+}
+
+impl<'a> Drop for RemoteAlloc<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            VirtualFreeEx(
+                self.process.handle(),
+                self.address as *mut c_void,
+                0,
+                MEM_RELEASE,
+            );
+        }
+    }
+}